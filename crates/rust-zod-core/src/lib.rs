@@ -1,13 +1,21 @@
 // use proptest::array;
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use regex::Regex;
 use serde_json::json;
+use serde_json::Number;
 use serde_json::Value;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone)]
 pub struct ObjectSchema {
     pub properties: HashMap<String, Schema>,
     pub required: Vec<String>,
     pub additional_properties: bool,
+    pub default: Option<Value>,
+    pub coerce: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -15,17 +23,122 @@ pub struct ArraySchema {
     pub items: Option<Box<Schema>>,
     pub min_items: Option<usize>,
     pub max_items: Option<usize>,
+    pub prefix_items: Option<Vec<Schema>>,
+    pub additional_items: bool,
+    pub contains: Option<Box<Schema>>,
+    pub min_contains: Option<usize>,
+    pub max_contains: Option<usize>,
+    pub default: Option<Value>,
+    pub coerce: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct StringSchema {
     pub min_length: Option<usize>,
     pub max_length: Option<usize>,
+    pub pattern: Option<Regex>,
+    pub format: Option<Format>,
+    pub default: Option<Value>,
 }
+
+// Named string formats, similar in spirit to JSON Schema's `format` keyword.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Email,
+    DateTime,
+    Date,
+    Time,
+    Uri,
+    Uuid,
+    Ipv4,
+    Ipv6,
+}
+
+impl Format {
+    fn name(self) -> &'static str {
+        match self {
+            Format::Email => "email",
+            Format::DateTime => "date-time",
+            Format::Date => "date",
+            Format::Time => "time",
+            Format::Uri => "uri",
+            Format::Uuid => "uuid",
+            Format::Ipv4 => "ipv4",
+            Format::Ipv6 => "ipv6",
+        }
+    }
+
+    fn matches(self, s: &str) -> bool {
+        match self {
+            Format::Email => email_regex().is_match(s),
+            Format::Uri => uri_regex().is_match(s),
+            Format::Uuid => uuid_regex().is_match(s),
+            Format::Ipv4 => s.parse::<Ipv4Addr>().is_ok(),
+            Format::Ipv6 => s.parse::<Ipv6Addr>().is_ok(),
+            Format::DateTime => DateTime::parse_from_rfc3339(s).is_ok(),
+            Format::Date => NaiveDate::parse_from_str(s, "%Y-%m-%d").is_ok(),
+            Format::Time => {
+                NaiveTime::parse_from_str(s, "%H:%M:%S%.f").is_ok()
+                    || NaiveTime::parse_from_str(s, "%H:%M:%S").is_ok()
+            }
+        }
+    }
+}
+
+fn email_regex() -> &'static Regex {
+    static EMAIL: OnceLock<Regex> = OnceLock::new();
+    EMAIL.get_or_init(|| Regex::new(r"^[^\s@]+@[^\s@]+\.[^\s@]+$").unwrap())
+}
+
+fn uri_regex() -> &'static Regex {
+    static URI: OnceLock<Regex> = OnceLock::new();
+    URI.get_or_init(|| Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.\-]*:.+$").unwrap())
+}
+
+fn uuid_regex() -> &'static Regex {
+    static UUID: OnceLock<Regex> = OnceLock::new();
+    UUID.get_or_init(|| {
+        Regex::new(r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$")
+            .unwrap()
+    })
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NumBound {
+    U64(u64),
+    I64(i64),
+    F64(f64),
+}
+
+impl NumBound {
+    fn to_json(self) -> Value {
+        match self {
+            NumBound::U64(v) => json!(v),
+            NumBound::I64(v) => json!(v),
+            NumBound::F64(v) => json!(v),
+        }
+    }
+}
+
+// Returned by `NumberBuilder::multiple_of` when given a zero divisor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDivisorError;
+
+impl std::fmt::Display for InvalidDivisorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "multiple_of divisor must not be zero")
+    }
+}
+
+impl std::error::Error for InvalidDivisorError {}
+
 #[derive(Debug, Clone)]
 pub struct NumberSchema {
-    pub min: Option<f64>,
-    pub max: Option<f64>,
+    pub min: Option<NumBound>,
+    pub max: Option<NumBound>,
+    pub exclusive_min: Option<NumBound>,
+    pub exclusive_max: Option<NumBound>,
+    pub multiple_of: Option<f64>,
+    pub default: Option<Value>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,36 +146,86 @@ pub enum Schema {
     String(StringSchema),
     Number(NumberSchema),
     Boolean,
+    Null,
     Object(ObjectSchema),
     Array(ArraySchema),
+    Enum(Vec<Value>),
+    Const(Value),
 }
 
 #[derive(Debug, Clone)]
 pub struct ValidationError {
     pub path: Vec<PathSegment>,
+    // Which schema keyword failed, e.g. ["properties", "age", "minimum"].
+    pub schema_path: Vec<String>,
     pub code: ErrorCode,
     pub message: String, // to change it back to normal just leave this and delete the rest
     pub expected: Option<Value>,
     pub received: Option<Value>,
 }
 
+impl ValidationError {
+    /// Renders `path` as an RFC 6901 JSON Pointer, e.g. `/items/1/email`.
+    pub fn instance_pointer(&self) -> String {
+        render_json_pointer(self.path.iter().map(PathSegment::to_pointer_token))
+    }
+
+    /// Renders `schema_path` as a JSON Pointer into the schema, e.g. `/properties/age/minimum`.
+    pub fn schema_pointer(&self) -> String {
+        render_json_pointer(self.schema_path.iter().map(|s| escape_pointer_token(s)))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum PathSegment {
     Key(String),
     Index(usize),
 }
 
+impl PathSegment {
+    fn to_pointer_token(&self) -> String {
+        match self {
+            PathSegment::Key(k) => escape_pointer_token(k),
+            PathSegment::Index(i) => i.to_string(),
+        }
+    }
+}
+
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn render_json_pointer<I: IntoIterator<Item = String>>(tokens: I) -> String {
+    let mut pointer = String::new();
+    for token in tokens {
+        pointer.push('/');
+        pointer.push_str(&token);
+    }
+    pointer
+}
+
 #[derive(Debug, Clone)]
 pub enum ErrorCode {
     InvalidType,
     MinLength,
     MaxLength,
+    Pattern,
+    Format,
     Min,
     Max,
+    ExclusiveMin,
+    ExclusiveMax,
+    MultipleOf,
     Required,
     MinItems,
     MaxItems,
     AdditionalProperty,
+    AdditionalItem,
+    Contains,
+    MinContains,
+    MaxContains,
+    Enum,
+    Const,
 }
 
 impl Schema {
@@ -71,6 +234,9 @@ impl Schema {
         StringBuilder {
             min_length: None,
             max_length: None,
+            pattern: None,
+            format: None,
+            default: None,
         }
     }
 
@@ -78,6 +244,10 @@ impl Schema {
         NumberBuilder {
             min: None,
             max: None,
+            exclusive_min: None,
+            exclusive_max: None,
+            multiple_of: None,
+            default: None,
         }
     }
 
@@ -86,6 +256,8 @@ impl Schema {
             properties: HashMap::new(),
             required: Vec::new(),
             additional_properties: true,
+            default: None,
+            coerce: false,
         }
     }
 
@@ -94,14 +266,35 @@ impl Schema {
             items: None,
             min_items: None,
             max_items: None,
+            prefix_items: None,
+            additional_items: true,
+            contains: None,
+            min_contains: None,
+            max_contains: None,
+            default: None,
+            coerce: false,
         }
     }
+
+    /// Restricts a value to one of a fixed set of allowed values, e.g. a status field
+    /// limited to `["active", "inactive"]`.
+    pub fn enumeration(allowed: Vec<Value>) -> Schema {
+        Schema::Enum(allowed)
+    }
+
+    /// The single-value case of `enumeration`: a literal that the value must equal exactly.
+    pub fn constant(value: Value) -> Schema {
+        Schema::Const(value)
+    }
 }
 
 // The builder for strings
 pub struct StringBuilder {
     min_length: Option<usize>,
     max_length: Option<usize>,
+    pattern: Option<Regex>,
+    format: Option<Format>,
+    default: Option<Value>,
 }
 
 impl StringBuilder {
@@ -116,27 +309,112 @@ impl StringBuilder {
         self
     }
 
+    // Compiles eagerly so a bad pattern surfaces here instead of panicking during validation.
+    pub fn pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.pattern = Some(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    pub fn format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    // Used by `validate_and_fill` to populate a missing property before validation runs.
+    pub fn default(mut self, value: Value) -> Self {
+        self.default = Some(value);
+        self
+    }
+
     // Convert builder to final schema
     pub fn build(self) -> Schema {
         Schema::String(StringSchema {
             min_length: self.min_length,
             max_length: self.max_length,
+            pattern: self.pattern,
+            format: self.format,
+            default: self.default,
         })
     }
 }
 
 pub struct NumberBuilder {
-    min: Option<f64>,
-    max: Option<f64>,
+    min: Option<NumBound>,
+    max: Option<NumBound>,
+    exclusive_min: Option<NumBound>,
+    exclusive_max: Option<NumBound>,
+    multiple_of: Option<f64>,
+    default: Option<Value>,
 }
 
 impl NumberBuilder {
     pub fn min(mut self, min: f64) -> Self {
-        self.min = Some(min);
+        self.min = Some(NumBound::F64(min));
         self
     }
     pub fn max(mut self, max: f64) -> Self {
-        self.max = Some(max);
+        self.max = Some(NumBound::F64(max));
+        self
+    }
+
+    // Integer-exact variants so bounds near/beyond 2^53 don't round-trip through f64.
+    pub fn min_i64(mut self, min: i64) -> Self {
+        self.min = Some(NumBound::I64(min));
+        self
+    }
+    pub fn max_i64(mut self, max: i64) -> Self {
+        self.max = Some(NumBound::I64(max));
+        self
+    }
+    pub fn min_u64(mut self, min: u64) -> Self {
+        self.min = Some(NumBound::U64(min));
+        self
+    }
+    pub fn max_u64(mut self, max: u64) -> Self {
+        self.max = Some(NumBound::U64(max));
+        self
+    }
+
+    pub fn exclusive_min(mut self, min: f64) -> Self {
+        self.exclusive_min = Some(NumBound::F64(min));
+        self
+    }
+    pub fn exclusive_max(mut self, max: f64) -> Self {
+        self.exclusive_max = Some(NumBound::F64(max));
+        self
+    }
+
+    // Integer-exact variants so bounds near/beyond 2^53 don't round-trip through f64.
+    pub fn exclusive_min_i64(mut self, min: i64) -> Self {
+        self.exclusive_min = Some(NumBound::I64(min));
+        self
+    }
+    pub fn exclusive_max_i64(mut self, max: i64) -> Self {
+        self.exclusive_max = Some(NumBound::I64(max));
+        self
+    }
+    pub fn exclusive_min_u64(mut self, min: u64) -> Self {
+        self.exclusive_min = Some(NumBound::U64(min));
+        self
+    }
+    pub fn exclusive_max_u64(mut self, max: u64) -> Self {
+        self.exclusive_max = Some(NumBound::U64(max));
+        self
+    }
+
+    // Rejects a zero divisor so a bad builder input surfaces here instead of making every
+    // finite number fail the check.
+    pub fn multiple_of(mut self, divisor: f64) -> Result<Self, InvalidDivisorError> {
+        if divisor == 0.0 {
+            return Err(InvalidDivisorError);
+        }
+        self.multiple_of = Some(divisor);
+        Ok(self)
+    }
+
+    // Used by `validate_and_fill` to populate a missing property before validation runs.
+    pub fn default(mut self, value: Value) -> Self {
+        self.default = Some(value);
         self
     }
 
@@ -144,6 +422,10 @@ impl NumberBuilder {
         Schema::Number(NumberSchema {
             min: self.min,
             max: self.max,
+            exclusive_min: self.exclusive_min,
+            exclusive_max: self.exclusive_max,
+            multiple_of: self.multiple_of,
+            default: self.default,
         })
     }
 }
@@ -152,6 +434,8 @@ pub struct ObjectBuilder {
     properties: HashMap<String, Schema>,
     required: Vec<String>,
     additional_properties: bool,
+    default: Option<Value>,
+    coerce: bool,
 }
 
 impl ObjectBuilder {
@@ -169,11 +453,26 @@ impl ObjectBuilder {
         self
     }
 
+    // Used by `validate_and_fill` to populate a missing property before validation runs.
+    pub fn default(mut self, value: Value) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    // Opt-in: lets `validate_with_coercion` attempt safe scalar conversions (numeric
+    // strings to numbers, "true"/"false" to booleans) on this object's properties.
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
     pub fn build(self) -> Schema {
         Schema::Object(ObjectSchema {
             properties: self.properties,
             required: self.required,
             additional_properties: self.additional_properties,
+            default: self.default,
+            coerce: self.coerce,
         })
     }
 }
@@ -182,6 +481,13 @@ pub struct ArrayBuilder {
     items: Option<Box<Schema>>,
     min_items: Option<usize>,
     max_items: Option<usize>,
+    prefix_items: Option<Vec<Schema>>,
+    additional_items: bool,
+    contains: Option<Box<Schema>>,
+    min_contains: Option<usize>,
+    max_contains: Option<usize>,
+    default: Option<Value>,
+    coerce: bool,
 }
 
 impl ArrayBuilder {
@@ -200,27 +506,439 @@ impl ArrayBuilder {
         self
     }
 
+    // Positional tuple validation: element `i` is checked against `prefix_items[i]`.
+    pub fn prefix_items(mut self, schemas: Vec<Schema>) -> Self {
+        self.prefix_items = Some(schemas);
+        self
+    }
+
+    // Whether elements beyond `prefix_items` (with no trailing `items` schema) are allowed.
+    pub fn additional_items(mut self, allowed: bool) -> Self {
+        self.additional_items = allowed;
+        self
+    }
+
+    pub fn contains(mut self, schema: Schema) -> Self {
+        self.contains = Some(Box::new(schema));
+        self
+    }
+
+    pub fn min_contains(mut self, min: usize) -> Self {
+        self.min_contains = Some(min);
+        self
+    }
+
+    pub fn max_contains(mut self, max: usize) -> Self {
+        self.max_contains = Some(max);
+        self
+    }
+
+    // Used by `validate_and_fill` to populate a missing property before validation runs.
+    pub fn default(mut self, value: Value) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    // Opt-in: lets `validate_with_coercion` attempt safe scalar conversions (numeric strings to
+    // numbers, "true"/"false" to booleans) on this array's items.
+    pub fn coerce(mut self) -> Self {
+        self.coerce = true;
+        self
+    }
+
     pub fn build(self) -> Schema {
         Schema::Array(ArraySchema {
             items: self.items,
             min_items: self.min_items,
             max_items: self.max_items,
+            prefix_items: self.prefix_items,
+            additional_items: self.additional_items,
+            contains: self.contains,
+            min_contains: self.min_contains,
+            max_contains: self.max_contains,
+            default: self.default,
+            coerce: self.coerce,
         })
     }
 }
 
+// `value % divisor` doesn't round to the nearest multiple, so an exact multiple can surface
+// as a remainder near zero *or* near `divisor` (e.g. 0.3 % 0.1 ~= 0.09999999999999998).
+fn is_multiple_of(value: f64, divisor: f64) -> bool {
+    let remainder = value % divisor;
+    let tolerance = f64::EPSILON * value.abs().max(divisor.abs());
+    remainder.abs() < tolerance || (divisor.abs() - remainder.abs()) < tolerance
+}
+
+// Compares a JSON number against a bound without coercing both sides to f64, so integer
+// bounds near/beyond 2^53 stay exact. Mirrors the cross-type approach of the `num-cmp` crate.
+fn num_cmp(n: &Number, bound: NumBound) -> Ordering {
+    if let Some(u) = n.as_u64() {
+        cmp_num_bound(NumBound::U64(u), bound)
+    } else if let Some(i) = n.as_i64() {
+        cmp_num_bound(NumBound::I64(i), bound)
+    } else {
+        cmp_num_bound(NumBound::F64(n.as_f64().unwrap()), bound)
+    }
+}
+
+fn cmp_num_bound(left: NumBound, right: NumBound) -> Ordering {
+    match (left, right) {
+        (NumBound::U64(a), NumBound::U64(b)) => a.cmp(&b),
+        (NumBound::I64(a), NumBound::I64(b)) => a.cmp(&b),
+        (NumBound::F64(a), NumBound::F64(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        (NumBound::U64(a), NumBound::I64(b)) => {
+            if b < 0 {
+                Ordering::Greater
+            } else {
+                a.cmp(&(b as u64))
+            }
+        }
+        (NumBound::I64(a), NumBound::U64(b)) => cmp_num_bound(NumBound::U64(b), NumBound::I64(a)).reverse(),
+        (NumBound::U64(a), NumBound::F64(b)) => cmp_u64_f64(a, b),
+        (NumBound::F64(a), NumBound::U64(b)) => cmp_u64_f64(b, a).reverse(),
+        (NumBound::I64(a), NumBound::F64(b)) => cmp_i64_f64(a, b),
+        (NumBound::F64(a), NumBound::I64(b)) => cmp_i64_f64(b, a).reverse(),
+    }
+}
+
+// Exact comparison of a u64 against an f64: checks the float's sign, magnitude and
+// integrality instead of casting the u64 into (possibly lossy) f64 space.
+fn cmp_u64_f64(a: u64, b: f64) -> Ordering {
+    if b.is_nan() {
+        return Ordering::Greater;
+    }
+    if b < 0.0 {
+        return Ordering::Greater;
+    }
+    if b >= 18_446_744_073_709_551_616.0 {
+        // 2^64, beyond u64::MAX
+        return Ordering::Less;
+    }
+    let b_floor = b.floor();
+    let b_int = b_floor as u64;
+    match a.cmp(&b_int) {
+        Ordering::Equal if b_floor != b => Ordering::Less,
+        other => other,
+    }
+}
+
+fn cmp_i64_f64(a: i64, b: f64) -> Ordering {
+    if b.is_nan() {
+        return Ordering::Greater;
+    }
+    if b >= 9_223_372_036_854_775_808.0 {
+        // 2^63, beyond i64::MAX
+        return Ordering::Less;
+    }
+    if b < -9_223_372_036_854_775_808.0 {
+        return Ordering::Greater;
+    }
+    let b_floor = b.floor();
+    let b_int = b_floor as i64;
+    match a.cmp(&b_int) {
+        Ordering::Equal if b_floor != b => Ordering::Less,
+        other => other,
+    }
+}
+
+fn value_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::Null => "null",
+    }
+}
+
+// Builds the same flattened `Validator` tree `CompiledSchema` caches, so this and
+// `CompiledSchema::validate` share one error-collecting walk instead of two hand-kept copies.
+pub fn validate(schema: &Schema, value: &Value) -> Result<(), Vec<ValidationError>> {
+    let validator = Validator::from_schema(schema);
+    let mut errors = Vec::new();
+    let mut path = Vec::new();
+    let mut schema_path = Vec::new();
+
+    validate_recursive(&validator, value, &mut path, &mut schema_path, &mut errors);
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// A single failure in a [`BasicOutput`] report, modeled after JSON Schema's "basic" output
+/// format: a flat, serializable unit instead of the nested `PathSegment` walk.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BasicOutputUnit {
+    pub keyword_location: String,
+    pub instance_location: String,
+    pub error: String,
+    pub expected: Option<Value>,
+    pub received: Option<Value>,
+}
+
+/// Stable, serializable validation report for API consumers who want a JSON error
+/// envelope rather than walking [`ValidationError::path`]/`schema_path` themselves.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct BasicOutput {
+    pub valid: bool,
+    pub errors: Vec<BasicOutputUnit>,
+}
+
+pub fn validate_basic(schema: &Schema, value: &Value) -> BasicOutput {
+    match validate(schema, value) {
+        Ok(()) => BasicOutput {
+            valid: true,
+            errors: Vec::new(),
+        },
+        Err(errors) => BasicOutput {
+            valid: false,
+            errors: errors
+                .iter()
+                .map(|e| BasicOutputUnit {
+                    keyword_location: e.schema_pointer(),
+                    instance_location: e.instance_pointer(),
+                    error: e.message.clone(),
+                    expected: e.expected.clone(),
+                    received: e.received.clone(),
+                })
+                .collect(),
+        },
+    }
+}
+
+// A precomputed validator tree: regexes are already compiled eagerly by the builders, so the
+// work left to do up front is flattening `required` into a lookup set and recursing once
+// instead of re-walking `Schema`'s builder-shaped structure on every call.
+#[derive(Debug, Clone)]
+enum Validator {
+    String(StringSchema),
+    Number(NumberSchema),
+    Boolean,
+    Null,
+    Enum(Vec<Value>),
+    Const(Value),
+    Object {
+        properties: HashMap<String, Validator>,
+        required: std::collections::HashSet<String>,
+        additional_properties: bool,
+    },
+    Array {
+        items: Option<Box<Validator>>,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+        prefix_items: Option<Vec<Validator>>,
+        additional_items: bool,
+        contains: Option<Box<Validator>>,
+        min_contains: Option<usize>,
+        max_contains: Option<usize>,
+    },
+}
+
+impl Validator {
+    fn from_schema(schema: &Schema) -> Validator {
+        match schema {
+            Schema::String(s) => Validator::String(s.clone()),
+            Schema::Number(n) => Validator::Number(n.clone()),
+            Schema::Boolean => Validator::Boolean,
+            Schema::Null => Validator::Null,
+            Schema::Enum(allowed) => Validator::Enum(allowed.clone()),
+            Schema::Const(value) => Validator::Const(value.clone()),
+            Schema::Object(o) => Validator::Object {
+                properties: o
+                    .properties
+                    .iter()
+                    .map(|(k, v)| (k.clone(), Validator::from_schema(v)))
+                    .collect(),
+                required: o.required.iter().cloned().collect(),
+                additional_properties: o.additional_properties,
+            },
+            Schema::Array(a) => Validator::Array {
+                items: a.items.as_ref().map(|s| Box::new(Validator::from_schema(s))),
+                min_items: a.min_items,
+                max_items: a.max_items,
+                prefix_items: a
+                    .prefix_items
+                    .as_ref()
+                    .map(|schemas| schemas.iter().map(Validator::from_schema).collect()),
+                additional_items: a.additional_items,
+                contains: a
+                    .contains
+                    .as_ref()
+                    .map(|s| Box::new(Validator::from_schema(s))),
+                min_contains: a.min_contains,
+                max_contains: a.max_contains,
+            },
+        }
+    }
+
+    // Short-circuits on the first failure; never allocates a `ValidationError`.
+    fn is_valid(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Validator::String(s), Value::String(text)) => {
+                if s.min_length.is_some_and(|min| text.len() < min) {
+                    return false;
+                }
+                if s.max_length.is_some_and(|max| text.len() > max) {
+                    return false;
+                }
+                if s.pattern.as_ref().is_some_and(|pattern| !pattern.is_match(text)) {
+                    return false;
+                }
+                if s.format.is_some_and(|format| !format.matches(text)) {
+                    return false;
+                }
+                true
+            }
+
+            (Validator::Number(n), Value::Number(num)) => {
+                if n.min.is_some_and(|min| num_cmp(num, min) == Ordering::Less) {
+                    return false;
+                }
+                if n.max.is_some_and(|max| num_cmp(num, max) == Ordering::Greater) {
+                    return false;
+                }
+                if n.exclusive_min
+                    .is_some_and(|min| num_cmp(num, min) != Ordering::Greater)
+                {
+                    return false;
+                }
+                if n.exclusive_max
+                    .is_some_and(|max| num_cmp(num, max) != Ordering::Less)
+                {
+                    return false;
+                }
+                if n.multiple_of
+                    .is_some_and(|divisor| !is_multiple_of(num.as_f64().unwrap(), divisor))
+                {
+                    return false;
+                }
+                true
+            }
+
+            (Validator::Boolean, Value::Bool(_)) => true,
+            (Validator::Null, Value::Null) => true,
+            (Validator::Enum(allowed), v) => allowed.contains(v),
+            (Validator::Const(expected), v) => v == expected,
+
+            (
+                Validator::Object {
+                    properties,
+                    required,
+                    additional_properties,
+                },
+                Value::Object(obj),
+            ) => {
+                if !required.iter().all(|key| obj.contains_key(key)) {
+                    return false;
+                }
+                for (key, val) in obj {
+                    if let Some(prop_validator) = properties.get(key) {
+                        if !prop_validator.is_valid(val) {
+                            return false;
+                        }
+                    } else if !additional_properties {
+                        return false;
+                    }
+                }
+                true
+            }
+
+            (
+                Validator::Array {
+                    items,
+                    min_items,
+                    max_items,
+                    prefix_items,
+                    additional_items,
+                    contains,
+                    min_contains,
+                    max_contains,
+                },
+                Value::Array(arr),
+            ) => {
+                if min_items.is_some_and(|min| arr.len() < min) {
+                    return false;
+                }
+                if max_items.is_some_and(|max| arr.len() > max) {
+                    return false;
+                }
+
+                let prefix_len = prefix_items.as_ref().map_or(0, Vec::len);
+                for (i, item) in arr.iter().enumerate() {
+                    if i < prefix_len {
+                        if !prefix_items.as_ref().unwrap()[i].is_valid(item) {
+                            return false;
+                        }
+                    } else if let Some(item_validator) = items {
+                        if !item_validator.is_valid(item) {
+                            return false;
+                        }
+                    } else if !additional_items {
+                        return false;
+                    }
+                }
+
+                if let Some(contains_validator) = contains {
+                    let matched = arr.iter().filter(|item| contains_validator.is_valid(item)).count();
+                    let min = min_contains.unwrap_or(1);
+                    if matched < min {
+                        return false;
+                    }
+                    if max_contains.is_some_and(|max| matched > max) {
+                        return false;
+                    }
+                }
+
+                true
+            }
+
+            _ => false,
+        }
+    }
+}
+
+fn schema_type_name(validator: &Validator) -> &'static str {
+    match validator {
+        Validator::String(_) => "string",
+        Validator::Number(_) => "number",
+        Validator::Boolean => "boolean",
+        Validator::Null => "null",
+        Validator::Object { .. } => "object",
+        Validator::Array { .. } => "array",
+        Validator::Enum(_) => "enum",
+        Validator::Const(_) => "const",
+    }
+}
+
+// Error-collecting walk over the flattened `Validator` tree, shared by both `validate` (which
+// builds a throwaway tree via `Validator::from_schema`) and `CompiledSchema::validate` (which
+// reuses the tree it precomputed) -- one traversal kept in sync with `Validator::is_valid`
+// instead of two.
 fn validate_recursive(
-    schema: &Schema,
+    validator: &Validator,
     value: &Value,
     path: &mut Vec<PathSegment>,
+    schema_path: &mut Vec<String>,
     errors: &mut Vec<ValidationError>,
 ) {
-    match (schema, value) {
-        (Schema::String(string_schema), Value::String(s)) => {
+    let at_keyword = |schema_path: &Vec<String>, keyword: &str| {
+        let mut sp = schema_path.clone();
+        sp.push(keyword.to_string());
+        sp
+    };
+
+    match (validator, value) {
+        (Validator::String(string_schema), Value::String(s)) => {
             if let Some(min) = string_schema.min_length {
                 if s.len() < min {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "minLength"),
                         code: ErrorCode::MinLength,
                         message: format!("String must be at least {} characters", min),
                         expected: Some(json!({"min": min})),
@@ -232,6 +950,7 @@ fn validate_recursive(
                 if s.len() > max {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "maxLength"),
                         code: ErrorCode::MaxLength,
                         message: format!("String must be at most {} characters", max),
                         expected: Some(json!({"max": max})),
@@ -239,45 +958,139 @@ fn validate_recursive(
                     });
                 }
             }
+            if let Some(pattern) = &string_schema.pattern {
+                if !pattern.is_match(s) {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "pattern"),
+                        code: ErrorCode::Pattern,
+                        message: format!("String must match pattern {}", pattern.as_str()),
+                        expected: Some(json!({"pattern": pattern.as_str()})),
+                        received: Some(json!(s)),
+                    });
+                }
+            }
+            if let Some(format) = string_schema.format {
+                if !format.matches(s) {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "format"),
+                        code: ErrorCode::Format,
+                        message: format!("String must match format '{}'", format.name()),
+                        expected: Some(json!({"format": format.name()})),
+                        received: Some(json!(s)),
+                    });
+                }
+            }
         }
 
-        (Schema::Number(number_schema), Value::Number(n)) => {
-            let num = n.as_f64().unwrap();
+        (Validator::Number(number_schema), Value::Number(n)) => {
             if let Some(min) = number_schema.min {
-                if num < min {
+                if num_cmp(n, min) == Ordering::Less {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "minimum"),
                         code: ErrorCode::Min,
-                        message: format!("Number must be at least {}", min),
-                        expected: Some(json!({"min": min})),
-                        received: Some(json!(num)),
+                        message: format!("Number must be at least {}", min.to_json()),
+                        expected: Some(json!({"min": min.to_json()})),
+                        received: Some(json!(n)),
                     });
                 }
             }
             if let Some(max) = number_schema.max {
-                if num > max {
+                if num_cmp(n, max) == Ordering::Greater {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "maximum"),
                         code: ErrorCode::Max,
-                        message: format!("Number must be at most {}", max),
-                        expected: Some(json!({"max": max})),
-                        received: Some(json!(num)),
+                        message: format!("Number must be at most {}", max.to_json()),
+                        expected: Some(json!({"max": max.to_json()})),
+                        received: Some(json!(n)),
+                    });
+                }
+            }
+            if let Some(min) = number_schema.exclusive_min {
+                if num_cmp(n, min) != Ordering::Greater {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "exclusiveMinimum"),
+                        code: ErrorCode::ExclusiveMin,
+                        message: format!("Number must be greater than {}", min.to_json()),
+                        expected: Some(json!({"exclusiveMin": min.to_json()})),
+                        received: Some(json!(n)),
+                    });
+                }
+            }
+            if let Some(max) = number_schema.exclusive_max {
+                if num_cmp(n, max) != Ordering::Less {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "exclusiveMaximum"),
+                        code: ErrorCode::ExclusiveMax,
+                        message: format!("Number must be less than {}", max.to_json()),
+                        expected: Some(json!({"exclusiveMax": max.to_json()})),
+                        received: Some(json!(n)),
+                    });
+                }
+            }
+            if let Some(divisor) = number_schema.multiple_of {
+                if !is_multiple_of(n.as_f64().unwrap(), divisor) {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "multipleOf"),
+                        code: ErrorCode::MultipleOf,
+                        message: format!("Number must be a multiple of {}", divisor),
+                        expected: Some(json!({"multipleOf": divisor})),
+                        received: Some(json!(n)),
                     });
                 }
             }
         }
 
-        (Schema::Boolean, Value::Bool(_)) => {
-            // Boolean always valid if type matches
+        (Validator::Boolean, Value::Bool(_)) => {}
+
+        (Validator::Null, Value::Null) => {}
+
+        (Validator::Enum(allowed), v) => {
+            if !allowed.contains(v) {
+                errors.push(ValidationError {
+                    path: path.clone(),
+                    schema_path: at_keyword(schema_path, "enum"),
+                    code: ErrorCode::Enum,
+                    message: "Value is not one of the allowed values".to_string(),
+                    expected: Some(json!(allowed)),
+                    received: Some(v.clone()),
+                });
+            }
         }
 
-        (Schema::Object(object_schema), Value::Object(obj)) => {
-            // Check required properties
-            for required_key in &object_schema.required {
+        (Validator::Const(expected), v) => {
+            if v != expected {
+                errors.push(ValidationError {
+                    path: path.clone(),
+                    schema_path: at_keyword(schema_path, "const"),
+                    code: ErrorCode::Const,
+                    message: "Value does not match the required constant".to_string(),
+                    expected: Some(expected.clone()),
+                    received: Some(v.clone()),
+                });
+            }
+        }
+
+        (
+            Validator::Object {
+                properties,
+                required,
+                additional_properties,
+            },
+            Value::Object(obj),
+        ) => {
+            for required_key in required {
                 if !obj.contains_key(required_key) {
                     path.push(PathSegment::Key(required_key.clone()));
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "required"),
                         code: ErrorCode::Required,
                         message: format!("Required property '{}' is missing", required_key),
                         expected: None,
@@ -287,15 +1100,19 @@ fn validate_recursive(
                 }
             }
 
-            // Validate each property
             for (key, val) in obj {
                 path.push(PathSegment::Key(key.clone()));
 
-                if let Some(prop_schema) = object_schema.properties.get(key) {
-                    validate_recursive(prop_schema, val, path, errors);
-                } else if !object_schema.additional_properties {
+                if let Some(prop_validator) = properties.get(key) {
+                    schema_path.push("properties".to_string());
+                    schema_path.push(key.clone());
+                    validate_recursive(prop_validator, val, path, schema_path, errors);
+                    schema_path.pop();
+                    schema_path.pop();
+                } else if !additional_properties {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "additionalProperties"),
                         code: ErrorCode::AdditionalProperty,
                         message: format!("Additional property '{}' is not allowed", key),
                         expected: None,
@@ -307,12 +1124,24 @@ fn validate_recursive(
             }
         }
 
-        (Schema::Array(array_schema), Value::Array(arr)) => {
-            // Check min/max items
-            if let Some(min) = array_schema.min_items {
-                if arr.len() < min {
+        (
+            Validator::Array {
+                items,
+                min_items,
+                max_items,
+                prefix_items,
+                additional_items,
+                contains,
+                min_contains,
+                max_contains,
+            },
+            Value::Array(arr),
+        ) => {
+            if let Some(min) = min_items {
+                if arr.len() < *min {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "minItems"),
                         code: ErrorCode::MinItems,
                         message: format!("Array must have at least {} items", min),
                         expected: Some(json!({"minItems": min})),
@@ -321,10 +1150,11 @@ fn validate_recursive(
                 }
             }
 
-            if let Some(max) = array_schema.max_items {
-                if arr.len() > max {
+            if let Some(max) = max_items {
+                if arr.len() > *max {
                     errors.push(ValidationError {
                         path: path.clone(),
+                        schema_path: at_keyword(schema_path, "maxItems"),
                         code: ErrorCode::MaxItems,
                         message: format!("Array must have at most {} items", max),
                         expected: Some(json!({"maxItems": max})),
@@ -333,12 +1163,70 @@ fn validate_recursive(
                 }
             }
 
-            // Validate each item
-            if let Some(item_schema) = &array_schema.items {
-                for (i, item) in arr.iter().enumerate() {
-                    path.push(PathSegment::Index(i));
-                    validate_recursive(item_schema, item, path, errors);
-                    path.pop();
+            let prefix_len = prefix_items.as_ref().map_or(0, Vec::len);
+            for (i, item) in arr.iter().enumerate() {
+                path.push(PathSegment::Index(i));
+                if i < prefix_len {
+                    let prefix_validator = &prefix_items.as_ref().unwrap()[i];
+                    schema_path.push("prefixItems".to_string());
+                    schema_path.push(i.to_string());
+                    validate_recursive(prefix_validator, item, path, schema_path, errors);
+                    schema_path.pop();
+                    schema_path.pop();
+                } else if let Some(item_validator) = items {
+                    schema_path.push("items".to_string());
+                    validate_recursive(item_validator, item, path, schema_path, errors);
+                    schema_path.pop();
+                } else if !additional_items {
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "additionalItems"),
+                        code: ErrorCode::AdditionalItem,
+                        message: "Additional items are not allowed".to_string(),
+                        expected: None,
+                        received: Some(item.clone()),
+                    });
+                }
+                path.pop();
+            }
+
+            if let Some(contains_validator) = contains {
+                let matched = arr.iter().filter(|item| contains_validator.is_valid(item)).count();
+
+                let min = min_contains.unwrap_or(1);
+                if matched < min {
+                    let code = if min_contains.is_some() {
+                        ErrorCode::MinContains
+                    } else {
+                        ErrorCode::Contains
+                    };
+                    errors.push(ValidationError {
+                        path: path.clone(),
+                        schema_path: at_keyword(schema_path, "contains"),
+                        code,
+                        message: format!(
+                            "Array must contain at least {} matching item(s), found {}",
+                            min, matched
+                        ),
+                        expected: Some(json!({"minContains": min})),
+                        received: Some(json!(matched)),
+                    });
+                }
+
+                if let Some(max) = max_contains {
+                    if matched > *max {
+                        errors.push(ValidationError {
+                            path: path.clone(),
+                            schema_path: at_keyword(schema_path, "maxContains"),
+                            code: ErrorCode::MaxContains,
+                            message: format!(
+                                "Array must contain at most {} matching item(s), found {}",
+                                max, matched
+                            ),
+                            expected: Some(json!({"maxContains": max})),
+                            received: Some(json!(matched)),
+                        });
+                    }
                 }
             }
         }
@@ -346,10 +1234,11 @@ fn validate_recursive(
         _ => {
             errors.push(ValidationError {
                 path: path.clone(),
+                schema_path: at_keyword(schema_path, "type"),
                 code: ErrorCode::InvalidType,
                 message: format!(
                     "Expected {:?}, received {:?}",
-                    schema_type_name(schema),
+                    schema_type_name(validator),
                     value_type_name(value)
                 ),
                 expected: None,
@@ -359,40 +1248,219 @@ fn validate_recursive(
     }
 }
 
-fn schema_type_name(schema: &Schema) -> &'static str {
+/// A schema with up-front work (regex compilation already happens at build time; this adds
+/// a flattened validator tree and precomputed required-key sets) done once, so repeated
+/// validation of many documents against the same schema skips re-walking builder state.
+#[derive(Debug, Clone)]
+pub struct CompiledSchema {
+    validator: Validator,
+}
+
+impl Schema {
+    pub fn compile(&self) -> CompiledSchema {
+        CompiledSchema {
+            validator: Validator::from_schema(self),
+        }
+    }
+}
+
+impl CompiledSchema {
+    pub fn validate(&self, value: &Value) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        let mut path = Vec::new();
+        let mut schema_path = Vec::new();
+        validate_recursive(&self.validator, value, &mut path, &mut schema_path, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Yes/no validation that skips building `ValidationError`s entirely -- a meaningful
+    /// speedup for hot paths (e.g. bulk ingestion) that only need to know pass/fail.
+    pub fn is_valid(&self, value: &Value) -> bool {
+        self.validator.is_valid(value)
+    }
+}
+
+fn schema_default(schema: &Schema) -> Option<&Value> {
     match schema {
-        Schema::String(_) => "string",
-        Schema::Number(_) => "number",
-        Schema::Boolean => "boolean",
-        Schema::Object(_) => "object",
-        Schema::Array(_) => "array",
+        Schema::String(s) => s.default.as_ref(),
+        Schema::Number(n) => n.default.as_ref(),
+        Schema::Object(o) => o.default.as_ref(),
+        Schema::Array(a) => a.default.as_ref(),
+        _ => None,
     }
 }
 
-fn value_type_name(value: &Value) -> &'static str {
-    match value {
-        Value::String(_) => "string",
-        Value::Number(_) => "number",
-        Value::Bool(_) => "boolean",
-        Value::Object(_) => "object",
-        Value::Array(_) => "array",
-        Value::Null => "null",
+fn fill_defaults(schema: &Schema, value: &Value) -> Value {
+    match (schema, value) {
+        (Schema::Object(object_schema), Value::Object(obj)) => {
+            let mut filled = obj.clone();
+            for (key, prop_schema) in &object_schema.properties {
+                match filled.get(key) {
+                    Some(existing) => {
+                        let existing = existing.clone();
+                        filled.insert(key.clone(), fill_defaults(prop_schema, &existing));
+                    }
+                    None => {
+                        if let Some(default) = schema_default(prop_schema) {
+                            filled.insert(key.clone(), default.clone());
+                        }
+                    }
+                }
+            }
+            Value::Object(filled)
+        }
+        (Schema::Array(array_schema), Value::Array(arr)) => {
+            let prefix_len = array_schema.prefix_items.as_ref().map_or(0, Vec::len);
+            Value::Array(
+                arr.iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        if i < prefix_len {
+                            let prefix_schema = &array_schema.prefix_items.as_ref().unwrap()[i];
+                            fill_defaults(prefix_schema, item)
+                        } else if let Some(item_schema) = &array_schema.items {
+                            fill_defaults(item_schema, item)
+                        } else {
+                            item.clone()
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        _ => value.clone(),
     }
 }
 
-pub fn validate(schema: &Schema, value: &Value) -> Result<(), Vec<ValidationError>> {
-    let mut errors = Vec::new();
-    let mut path = Vec::new();
+/// Fills missing object properties from their schema's `default()` (recursively) before
+/// validating, and returns the filled value alongside the validation result.
+pub fn validate_and_fill(schema: &Schema, value: &Value) -> (Value, Result<(), Vec<ValidationError>>) {
+    let filled = fill_defaults(schema, value);
+    let result = validate(schema, &filled);
+    (filled, result)
+}
 
-    validate_recursive(schema, value, &mut path, &mut errors);
+fn coerce_scalar(schema: &Schema, value: &Value, pointer: &str, log: &mut Vec<String>) -> Value {
+    match (schema, value) {
+        // Tries integer parses first so large values stay exact (see `NumBound`/`num_cmp`)
+        // instead of always round-tripping through a lossy f64.
+        (Schema::Number(_), Value::String(s)) => {
+            if let Ok(n) = s.parse::<u64>() {
+                log.push(format!("coerced {} from string {:?} to number {}", pointer, s, n));
+                json!(n)
+            } else if let Ok(n) = s.parse::<i64>() {
+                log.push(format!("coerced {} from string {:?} to number {}", pointer, s, n));
+                json!(n)
+            } else {
+                match s.parse::<f64>() {
+                    // NaN/infinite parses (e.g. "nan", "inf") have no valid JSON number
+                    // representation, so leave them as the original string for validation to reject.
+                    Ok(n) if n.is_finite() => {
+                        log.push(format!("coerced {} from string {:?} to number {}", pointer, s, n));
+                        json!(n)
+                    }
+                    Ok(_) | Err(_) => value.clone(),
+                }
+            }
+        }
+        (Schema::Boolean, Value::String(s)) => match s.as_str() {
+            "true" => {
+                log.push(format!("coerced {} from string \"true\" to boolean true", pointer));
+                json!(true)
+            }
+            "false" => {
+                log.push(format!("coerced {} from string \"false\" to boolean false", pointer));
+                json!(false)
+            }
+            _ => value.clone(),
+        },
+        _ => value.clone(),
+    }
+}
 
-    if errors.is_empty() {
-        Ok(())
-    } else {
-        Err(errors)
+fn coerce_recursive(
+    schema: &Schema,
+    value: &Value,
+    path: &mut Vec<PathSegment>,
+    log: &mut Vec<String>,
+) -> Value {
+    match (schema, value) {
+        (Schema::Object(object_schema), Value::Object(obj)) => {
+            let mut result = obj.clone();
+            for (key, val) in obj.iter() {
+                if let Some(prop_schema) = object_schema.properties.get(key) {
+                    path.push(PathSegment::Key(key.clone()));
+                    let scalar_coerced = if object_schema.coerce {
+                        let pointer = render_json_pointer(path.iter().map(PathSegment::to_pointer_token));
+                        coerce_scalar(prop_schema, val, &pointer, log)
+                    } else {
+                        val.clone()
+                    };
+                    let fully_coerced = coerce_recursive(prop_schema, &scalar_coerced, path, log);
+                    result.insert(key.clone(), fully_coerced);
+                    path.pop();
+                }
+            }
+            Value::Object(result)
+        }
+        (Schema::Array(array_schema), Value::Array(arr)) => {
+            let prefix_len = array_schema.prefix_items.as_ref().map_or(0, Vec::len);
+            Value::Array(
+                arr.iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        path.push(PathSegment::Index(i));
+                        let coerced = if i < prefix_len {
+                            let prefix_schema = &array_schema.prefix_items.as_ref().unwrap()[i];
+                            let scalar_coerced = if array_schema.coerce {
+                                let pointer =
+                                    render_json_pointer(path.iter().map(PathSegment::to_pointer_token));
+                                coerce_scalar(prefix_schema, item, &pointer, log)
+                            } else {
+                                item.clone()
+                            };
+                            coerce_recursive(prefix_schema, &scalar_coerced, path, log)
+                        } else if let Some(item_schema) = &array_schema.items {
+                            let scalar_coerced = if array_schema.coerce {
+                                let pointer =
+                                    render_json_pointer(path.iter().map(PathSegment::to_pointer_token));
+                                coerce_scalar(item_schema, item, &pointer, log)
+                            } else {
+                                item.clone()
+                            };
+                            coerce_recursive(item_schema, &scalar_coerced, path, log)
+                        } else {
+                            item.clone()
+                        };
+                        path.pop();
+                        coerced
+                    })
+                    .collect(),
+            )
+        }
+        _ => value.clone(),
     }
 }
 
+/// Attempts safe scalar coercions (numeric strings to numbers, `"true"`/`"false"` to
+/// booleans) on properties of objects built with `ObjectBuilder::coerce()` and on items of
+/// arrays built with `ArrayBuilder::coerce()`, then validates.
+/// Useful for ingesting loosely-typed input (query params, form data) without pre-normalizing.
+/// Returns the coerced value, a log describing what was coerced, and the validation result.
+pub fn validate_with_coercion(
+    schema: &Schema,
+    value: &Value,
+) -> (Value, Vec<String>, Result<(), Vec<ValidationError>>) {
+    let mut log = Vec::new();
+    let mut path = Vec::new();
+    let coerced = coerce_recursive(schema, value, &mut path, &mut log);
+    let result = validate(schema, &coerced);
+    (coerced, log, result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -564,4 +1632,317 @@ mod tests {
             _ => panic!("Expected Key"),
         }
     }
+
+    #[test]
+    fn test_large_integer_bounds_are_exact() {
+        // 2^53 + 1 cannot be represented exactly as f64; a naive `as_f64` comparison
+        // would see this as equal to 2^53 and accept it.
+        let schema = Schema::number().max_u64(9_007_199_254_740_992).build(); // 2^53
+        let result = validate(&schema, &json!(9_007_199_254_740_993u64)); // 2^53 + 1
+        assert!(result.is_err());
+
+        let schema = Schema::number().min_i64(-10).build();
+        assert!(validate(&schema, &json!(-5)).is_ok());
+        assert!(validate(&schema, &json!(-20)).is_err());
+    }
+
+    #[test]
+    fn test_float_min_still_works() {
+        let schema = Schema::number().min(0.0).build();
+        assert!(validate(&schema, &json!(5)).is_ok());
+        assert!(validate(&schema, &json!(-1)).is_err());
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        let schema = Schema::number().exclusive_min(0.0).exclusive_max(10.0).build();
+        assert!(validate(&schema, &json!(5)).is_ok());
+        assert!(validate(&schema, &json!(0)).is_err());
+        assert!(validate(&schema, &json!(10)).is_err());
+    }
+
+    #[test]
+    fn test_exclusive_bounds_are_integer_exact() {
+        // 2^53 + 1 cannot be represented exactly as f64; a naive `as_f64` comparison
+        // would round the bound down to 2^53 and wrongly reject 2^53 as equal to it.
+        let schema = Schema::number().exclusive_max_u64(9_007_199_254_740_993).build(); // 2^53 + 1
+        assert!(validate(&schema, &json!(9_007_199_254_740_993u64)).is_err());
+        assert!(validate(&schema, &json!(9_007_199_254_740_992u64)).is_ok());
+
+        let schema = Schema::number().exclusive_min_i64(-10).build();
+        assert!(validate(&schema, &json!(-10)).is_err());
+        assert!(validate(&schema, &json!(-9)).is_ok());
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        let schema = Schema::number().multiple_of(0.1).unwrap().build();
+        assert!(validate(&schema, &json!(0.3)).is_ok());
+        assert!(validate(&schema, &json!(0.25)).is_err());
+    }
+
+    #[test]
+    fn test_multiple_of_rejects_zero_divisor() {
+        match Schema::number().multiple_of(0.0) {
+            Err(InvalidDivisorError) => {}
+            Ok(_) => panic!("expected multiple_of(0.0) to be rejected"),
+        }
+    }
+
+    #[test]
+    fn test_pattern() {
+        let schema = Schema::string().pattern(r"^\d{3}-\d{4}$").unwrap().build();
+        assert!(validate(&schema, &json!("555-1234")).is_ok());
+        assert!(validate(&schema, &json!("not-a-number")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_pattern_surfaces_error() {
+        let result = Schema::string().pattern("(unclosed");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_format_email_and_uuid() {
+        let email_schema = Schema::string().format(Format::Email).build();
+        assert!(validate(&email_schema, &json!("a@example.com")).is_ok());
+        assert!(validate(&email_schema, &json!("not-an-email")).is_err());
+
+        let uuid_schema = Schema::string().format(Format::Uuid).build();
+        assert!(validate(&uuid_schema, &json!("123e4567-e89b-12d3-a456-426614174000")).is_ok());
+        assert!(validate(&uuid_schema, &json!("not-a-uuid")).is_err());
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        let schema = Schema::string().format(Format::DateTime).build();
+        assert!(validate(&schema, &json!("2024-01-02T03:04:05Z")).is_ok());
+        assert!(validate(&schema, &json!("2024-01-02")).is_err());
+
+        let date_schema = Schema::string().format(Format::Date).build();
+        assert!(validate(&date_schema, &json!("2024-01-02")).is_ok());
+        assert!(validate(&date_schema, &json!("not-a-date")).is_err());
+    }
+
+    #[test]
+    fn test_tuple_validation() {
+        let schema = Schema::array()
+            .prefix_items(vec![
+                Schema::string().build(),
+                Schema::number().build(),
+            ])
+            .additional_items(false)
+            .build();
+
+        assert!(validate(&schema, &json!(["Alice", 30])).is_ok());
+        assert!(validate(&schema, &json!(["Alice", 30, "extra"])).is_err());
+        assert!(validate(&schema, &json!([30, "Alice"])).is_err());
+    }
+
+    #[test]
+    fn test_tuple_with_trailing_items_schema() {
+        let schema = Schema::array()
+            .prefix_items(vec![Schema::string().build()])
+            .items(Schema::number().build())
+            .build();
+
+        assert!(validate(&schema, &json!(["Alice", 1, 2, 3])).is_ok());
+        assert!(validate(&schema, &json!(["Alice", "not a number"])).is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let schema = Schema::array()
+            .contains(Schema::number().min(10.0).build())
+            .build();
+
+        assert!(validate(&schema, &json!([1, 2, 15])).is_ok());
+        assert!(validate(&schema, &json!([1, 2, 3])).is_err());
+    }
+
+    #[test]
+    fn test_min_max_contains() {
+        let schema = Schema::array()
+            .contains(Schema::number().min(10.0).build())
+            .min_contains(2)
+            .max_contains(3)
+            .build();
+
+        assert!(validate(&schema, &json!([11, 12])).is_ok());
+        assert!(validate(&schema, &json!([11])).is_err());
+        assert!(validate(&schema, &json!([11, 12, 13, 14])).is_err());
+    }
+
+    #[test]
+    fn test_instance_pointer_rendering() {
+        let schema = Schema::array()
+            .items(
+                Schema::object()
+                    .property("email", Schema::string().min_length(5).build())
+                    .required("email")
+                    .build(),
+            )
+            .build();
+
+        let data = json!([
+            {"email": "good@example.com"},
+            {"email": "bad"}
+        ]);
+
+        let errors = validate(&schema, &data).unwrap_err();
+        assert_eq!(errors[0].instance_pointer(), "/1/email");
+        assert_eq!(errors[0].schema_pointer(), "/items/properties/email/minLength");
+    }
+
+    #[test]
+    fn test_pointer_escapes_tilde_and_slash() {
+        let schema = Schema::object()
+            .property("a/b~c", Schema::string().min_length(5).build())
+            .build();
+
+        let data = json!({"a/b~c": "x"});
+        let errors = validate(&schema, &data).unwrap_err();
+        assert_eq!(errors[0].instance_pointer(), "/a~1b~0c");
+    }
+
+    #[test]
+    fn test_validate_basic() {
+        let schema = Schema::object()
+            .property("age", Schema::number().min(0.0).build())
+            .required("age")
+            .build();
+
+        let report = validate_basic(&schema, &json!({"age": -1}));
+        assert!(!report.valid);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].instance_location, "/age");
+        assert_eq!(report.errors[0].keyword_location, "/properties/age/minimum");
+
+        let ok_report = validate_basic(&schema, &json!({"age": 5}));
+        assert!(ok_report.valid);
+        assert!(ok_report.errors.is_empty());
+    }
+
+    #[test]
+    fn test_compiled_schema_is_valid() {
+        let compiled = Schema::object()
+            .property("name", Schema::string().min_length(1).build())
+            .property("age", Schema::number().min(0.0).build())
+            .required("name")
+            .build()
+            .compile();
+
+        assert!(compiled.is_valid(&json!({"name": "John", "age": 25})));
+        assert!(!compiled.is_valid(&json!({"age": 25})));
+        assert!(!compiled.is_valid(&json!({"name": "John", "age": -1})));
+    }
+
+    #[test]
+    fn test_compiled_schema_validate_matches_full_errors() {
+        let schema = Schema::array()
+            .items(Schema::string().min_length(1).build())
+            .min_items(1)
+            .build();
+        let compiled = schema.compile();
+
+        assert!(compiled.validate(&json!(["hello"])).is_ok());
+        assert!(compiled.validate(&json!([])).is_err());
+        assert_eq!(compiled.is_valid(&json!([])), compiled.validate(&json!([])).is_ok());
+    }
+
+    #[test]
+    fn test_null_schema() {
+        assert!(validate(&Schema::Null, &json!(null)).is_ok());
+        assert!(validate(&Schema::Null, &json!(0)).is_err());
+    }
+
+    #[test]
+    fn test_enum_schema() {
+        let schema = Schema::enumeration(vec![json!("active"), json!("inactive")]);
+        assert!(validate(&schema, &json!("active")).is_ok());
+        assert!(validate(&schema, &json!("pending")).is_err());
+    }
+
+    #[test]
+    fn test_const_schema() {
+        let schema = Schema::constant(json!("discriminator"));
+        assert!(validate(&schema, &json!("discriminator")).is_ok());
+        assert!(validate(&schema, &json!("other")).is_err());
+    }
+
+    #[test]
+    fn test_validate_and_fill_applies_defaults() {
+        let schema = Schema::object()
+            .property("name", Schema::string().build())
+            .property("role", Schema::string().default(json!("member")).build())
+            .required("name")
+            .build();
+
+        let (filled, result) = validate_and_fill(&schema, &json!({"name": "Alice"}));
+        assert!(result.is_ok());
+        assert_eq!(filled, json!({"name": "Alice", "role": "member"}));
+
+        // An explicitly provided value is left untouched.
+        let (filled, _) = validate_and_fill(&schema, &json!({"name": "Bob", "role": "admin"}));
+        assert_eq!(filled, json!({"name": "Bob", "role": "admin"}));
+    }
+
+    #[test]
+    fn test_validate_with_coercion() {
+        let schema = Schema::object()
+            .property("age", Schema::number().min(0.0).build())
+            .property("active", Schema::Boolean)
+            .coerce()
+            .build();
+
+        let (coerced, log, result) =
+            validate_with_coercion(&schema, &json!({"age": "30", "active": "true"}));
+
+        assert!(result.is_ok());
+        assert_eq!(coerced, json!({"age": 30, "active": true}));
+        assert_eq!(log.len(), 2);
+    }
+
+    #[test]
+    fn test_coercion_preserves_large_integer_exactness() {
+        let schema = Schema::object()
+            .property("n", Schema::number().max_u64(9_007_199_254_740_992).build())
+            .coerce()
+            .build();
+
+        let (coerced, _log, result) =
+            validate_with_coercion(&schema, &json!({"n": "9007199254740993"}));
+
+        assert_eq!(coerced, json!({"n": 9_007_199_254_740_993_u64}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_coercion_is_opt_in() {
+        let schema = Schema::object()
+            .property("age", Schema::number().build())
+            .build();
+
+        let (coerced, log, result) = validate_with_coercion(&schema, &json!({"age": "30"}));
+        assert!(log.is_empty());
+        assert_eq!(coerced, json!({"age": "30"}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_coercion_applies_to_array_items() {
+        let schema = Schema::object()
+            .property(
+                "nums",
+                Schema::array().items(Schema::number().build()).coerce().build(),
+            )
+            .build();
+
+        let (coerced, log, result) =
+            validate_with_coercion(&schema, &json!({"nums": ["1", "2", "3"]}));
+
+        assert!(result.is_ok());
+        assert_eq!(coerced, json!({"nums": [1, 2, 3]}));
+        assert_eq!(log.len(), 3);
+    }
 }